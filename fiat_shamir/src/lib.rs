@@ -1,4 +1,5 @@
 pub mod interface;
+use ark_ff::PrimeField;
 use interface::TranscriptInterface;
 use sha3::{Digest, Keccak256};
 
@@ -15,6 +16,26 @@ impl FiatShamirTranscript {
         response.append(msg);
         response
     }
+
+    /// Absorbs a domain-separation `label` before `msg`, so challenges drawn
+    /// for distinct protocol phases (e.g. GKR/sumcheck rounds vs. a Groth16
+    /// opening) can't be replayed across each other.
+    pub fn append_with_label(&mut self, label: &[u8], msg: Vec<u8>) {
+        self.append(label.to_vec());
+        self.append(msg);
+    }
+
+    /// Hashes the running state and reduces the 32-byte digest modulo `F`,
+    /// so callers don't have to re-implement the reduction themselves.
+    pub fn sample_field_element<F: PrimeField>(&mut self) -> F {
+        let bytes = self.sample();
+        F::from_be_bytes_mod_order(&bytes)
+    }
+
+    /// `n` field elements drawn the same way as `sample_field_element`.
+    pub fn sample_n_field_elements<F: PrimeField>(&mut self, n: usize) -> Vec<F> {
+        (0..n).map(|_| self.sample_field_element()).collect()
+    }
 }
 
 impl TranscriptInterface for FiatShamirTranscript {
@@ -36,3 +57,46 @@ impl TranscriptInterface for FiatShamirTranscript {
         response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn test_append_with_label_separates_domains() {
+        let msg = b"same message".to_vec();
+
+        let mut transcript_a = FiatShamirTranscript::new(vec![]);
+        transcript_a.append_with_label(b"label-a", msg.clone());
+        let challenge_a: Fr = transcript_a.sample_field_element();
+
+        let mut transcript_b = FiatShamirTranscript::new(vec![]);
+        transcript_b.append_with_label(b"label-b", msg);
+        let challenge_b: Fr = transcript_b.sample_field_element();
+
+        assert_ne!(challenge_a, challenge_b);
+    }
+
+    #[test]
+    fn test_sample_field_element_is_deterministic() {
+        let mut transcript_a = FiatShamirTranscript::new(b"shared state".to_vec());
+        let mut transcript_b = FiatShamirTranscript::new(b"shared state".to_vec());
+
+        let challenge_a: Fr = transcript_a.sample_field_element();
+        let challenge_b: Fr = transcript_b.sample_field_element();
+
+        assert_eq!(challenge_a, challenge_b);
+    }
+
+    #[test]
+    fn test_sample_n_field_elements_matches_repeated_sampling() {
+        let mut transcript_a = FiatShamirTranscript::new(b"shared state".to_vec());
+        let batch: Vec<Fr> = transcript_a.sample_n_field_elements(3);
+
+        let mut transcript_b = FiatShamirTranscript::new(b"shared state".to_vec());
+        let repeated: Vec<Fr> = (0..3).map(|_| transcript_b.sample_field_element()).collect();
+
+        assert_eq!(batch, repeated);
+    }
+}
@@ -0,0 +1,32 @@
+use ark_ec::pairing::{Pairing, PairingOutput};
+
+/// Everything the prover needs, derived once per QAP during the trusted setup.
+pub struct ProvingKey<P: Pairing> {
+    pub alpha_g1: P::G1,
+    pub beta_g1: P::G1,
+    pub beta_g2: P::G2,
+    pub delta_g1: P::G1,
+    pub delta_g2: P::G2,
+    /// `[A_i(tau)]_1` for every wire `i`
+    pub a_query: Vec<P::G1>,
+    /// `[B_i(tau)]_1` for every wire `i`
+    pub b_query_g1: Vec<P::G1>,
+    /// `[B_i(tau)]_2` for every wire `i`
+    pub b_query_g2: Vec<P::G2>,
+    /// `[tau^i * t(tau) / delta]_1` for `i` in `0..(domain.size - 1)`, where
+    /// `domain` is `EvaluationDomain::new(num_constraints)`
+    pub h_query: Vec<P::G1>,
+    /// `[(beta*A_i(tau) + alpha*B_i(tau) + C_i(tau)) / delta]_1` for every private wire
+    pub l_query: Vec<P::G1>,
+}
+
+/// Everything the verifier needs to check a proof against public inputs.
+pub struct VerifyingKey<P: Pairing> {
+    /// `e([alpha]_1, [beta]_2)`, precomputed since it is independent of the proof
+    pub alpha_g1_beta_g2: PairingOutput<P>,
+    pub gamma_g2: P::G2,
+    pub delta_g2: P::G2,
+    /// `[(beta*A_i(tau) + alpha*B_i(tau) + C_i(tau)) / gamma]_1` for every public wire,
+    /// index 0 is the implicit constant-`1` wire
+    pub gamma_abc_g1: Vec<P::G1>,
+}
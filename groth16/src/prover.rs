@@ -0,0 +1,86 @@
+use ark_ec::pairing::Pairing;
+use ark_std::{rand::RngCore, UniformRand};
+use polynomial::{domain::EvaluationDomain, univariant::UnivariantPolynomial};
+
+use crate::{
+    keys::ProvingKey,
+    qap::Qap,
+    utils::{compute_quotient_over_domain, linear_combination_homomorphic_poly_eval_g1},
+};
+
+/// A Groth16 proof: the `A`, `B` commitments and the `C` commitment that
+/// folds in the quotient `h(x) * t(x) / delta`.
+pub struct Proof<P: Pairing> {
+    pub a: P::G1,
+    pub b: P::G2,
+    pub c: P::G1,
+}
+
+/// Combines `witness` with `qap` to produce `(A, B, C)`, using the
+/// coset-FFT to compute the quotient `h(x) = (A(x)*B(x) - C(x)) / t(x)`
+/// instead of a dense polynomial division. `A` and `B` are blinded with
+/// fresh `r`, `s` scalars (`A += r*delta`, `B += s*delta`) and `C` is
+/// corrected with the matching `s*A + r*B - r*s*delta` cross term, so the
+/// proof is witness-hiding rather than a deterministic function of `witness`.
+pub fn prove<P: Pairing, R: RngCore>(
+    pk: &ProvingKey<P>,
+    qap: &Qap<P::ScalarField>,
+    witness: &[P::ScalarField],
+    rng: &mut R,
+) -> Proof<P> {
+    assert_eq!(witness.len(), qap.num_variables());
+
+    let r = P::ScalarField::rand(rng);
+    let s = P::ScalarField::rand(rng);
+
+    let a = pk.alpha_g1
+        + witness
+            .iter()
+            .zip(&pk.a_query)
+            .fold(P::G1::default(), |acc, (w, a_i)| acc + a_i.mul_bigint(w.into_bigint()))
+        + pk.delta_g1.mul_bigint(r.into_bigint());
+
+    let b_g1 = pk.beta_g1
+        + witness
+            .iter()
+            .zip(&pk.b_query_g1)
+            .fold(P::G1::default(), |acc, (w, b_i)| acc + b_i.mul_bigint(w.into_bigint()))
+        + pk.delta_g1.mul_bigint(s.into_bigint());
+
+    let b = pk.beta_g2
+        + witness
+            .iter()
+            .zip(&pk.b_query_g2)
+            .fold(P::G2::default(), |acc, (w, b_i)| acc + b_i.mul_bigint(w.into_bigint()))
+        + pk.delta_g2.mul_bigint(s.into_bigint());
+
+    let c_from_l_query = witness
+        .iter()
+        .skip(qap.num_public_inputs)
+        .zip(&pk.l_query)
+        .fold(P::G1::default(), |acc, (w, l_i)| acc + l_i.mul_bigint(w.into_bigint()));
+
+    let domain = EvaluationDomain::<P::ScalarField>::new(qap.num_constraints);
+    let combine = |polys: &[UnivariantPolynomial<P::ScalarField>]| {
+        witness
+            .iter()
+            .zip(polys)
+            .fold(UnivariantPolynomial::from_coefficients_vec(vec![]), |acc, (w, p)| {
+                acc + p.clone() * *w
+            })
+    };
+    let a_poly = combine(&qap.a);
+    let b_poly = combine(&qap.b);
+    let c_poly = combine(&qap.c);
+
+    let h_poly = compute_quotient_over_domain(&a_poly, &b_poly, &c_poly, &domain);
+    let c_from_h = linear_combination_homomorphic_poly_eval_g1::<P>(&h_poly, pk.h_query.clone());
+
+    let c = c_from_l_query
+        + c_from_h
+        + a.mul_bigint(s.into_bigint())
+        + b_g1.mul_bigint(r.into_bigint())
+        - pk.delta_g1.mul_bigint((r * s).into_bigint());
+
+    Proof { a, b, c }
+}
@@ -0,0 +1,31 @@
+use ark_ff::PrimeField;
+use polynomial::univariant::UnivariantPolynomial;
+
+/// A Quadratic Arithmetic Program: for each wire `i` a polynomial `A_i`,
+/// `B_i`, `C_i` of degree `< num_constraints`, interpolated so that
+/// `A_i(j) / B_i(j) / C_i(j)` is the coefficient of wire `i` in constraint
+/// `j`. A valid assignment `w` satisfies, for every constraint `j`,
+/// `(sum_i w_i A_i(j)) * (sum_i w_i B_i(j)) = sum_i w_i C_i(j)`.
+///
+/// The constraint index `j` ranges over the points of
+/// `EvaluationDomain::new(num_constraints)` (the roots of unity of that
+/// domain's multiplicative subgroup), not the integer points `1..=num_constraints`
+/// that `generate_t_poly`'s doc comment describes: `generate_parameters` and
+/// `prove` build the vanishing polynomial and the `h(x) = (A*B - C)/t(x)`
+/// quotient via `EvaluationDomain`/`compute_quotient_over_domain`, so `a`/`b`/`c`
+/// must be interpolated consistently with that domain's points for the QAP
+/// identity to hold and the quotient to divide evenly.
+pub struct Qap<F: PrimeField> {
+    pub num_constraints: usize,
+    /// number of public wires, including the implicit constant-`1` wire at index 0
+    pub num_public_inputs: usize,
+    pub a: Vec<UnivariantPolynomial<F>>,
+    pub b: Vec<UnivariantPolynomial<F>>,
+    pub c: Vec<UnivariantPolynomial<F>>,
+}
+
+impl<F: PrimeField> Qap<F> {
+    pub fn num_variables(&self) -> usize {
+        self.a.len()
+    }
+}
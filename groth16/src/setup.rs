@@ -0,0 +1,117 @@
+use ark_ec::{pairing::Pairing, Group};
+use ark_ff::{Field, PrimeField};
+use ark_std::{rand::RngCore, UniformRand};
+use polynomial::domain::EvaluationDomain;
+
+use crate::{
+    keys::{ProvingKey, VerifyingKey},
+    qap::Qap,
+    utils::{
+        compute_l_i_of_tau_g1, generate_powers_of_tau_g1, generate_powers_of_tau_g1_alpha_or_beta,
+        generate_powers_of_tau_g2, linear_combination_homomorphic_poly_eval_g1,
+        linear_combination_homomorphic_poly_eval_g2,
+    },
+};
+
+/// Samples the toxic waste `(tau, alpha, beta, gamma, delta)` and derives the
+/// proving/verifying keys for `qap` by combining the powers-of-tau vectors
+/// with the per-wire `L_i(tau)` values, split between the public wires
+/// (divided by `gamma`) and the private wires (divided by `delta`).
+pub fn generate_parameters<P: Pairing, R: RngCore>(
+    qap: &Qap<P::ScalarField>,
+    rng: &mut R,
+) -> (ProvingKey<P>, VerifyingKey<P>) {
+    let domain = EvaluationDomain::<P::ScalarField>::new(qap.num_constraints);
+
+    let tau = P::ScalarField::rand(rng);
+    let alpha = P::ScalarField::rand(rng);
+    let beta = P::ScalarField::rand(rng);
+    let gamma = P::ScalarField::rand(rng);
+    let delta = P::ScalarField::rand(rng);
+
+    let gamma_inv = gamma.inverse().unwrap();
+    let delta_inv = delta.inverse().unwrap();
+
+    let generator_g1 = P::G1::generator();
+    let generator_g2 = P::G2::generator();
+
+    let alpha_g1 = generator_g1.mul_bigint(alpha.into_bigint());
+    let beta_g1 = generator_g1.mul_bigint(beta.into_bigint());
+    let beta_g2 = generator_g2.mul_bigint(beta.into_bigint());
+    let delta_g1 = generator_g1.mul_bigint(delta.into_bigint());
+    let delta_g2 = generator_g2.mul_bigint(delta.into_bigint());
+    let gamma_g2 = generator_g2.mul_bigint(gamma.into_bigint());
+
+    let t_g1 = generate_powers_of_tau_g1::<P>(tau, domain.size);
+    let t_g2 = generate_powers_of_tau_g2::<P>(tau, domain.size);
+    let alpha_t_g1 = generate_powers_of_tau_g1_alpha_or_beta::<P>(tau, alpha, domain.size);
+    let beta_t_g1 = generate_powers_of_tau_g1_alpha_or_beta::<P>(tau, beta, domain.size);
+
+    let mut a_query = Vec::with_capacity(qap.num_variables());
+    let mut b_query_g1 = Vec::with_capacity(qap.num_variables());
+    let mut b_query_g2 = Vec::with_capacity(qap.num_variables());
+    let mut gamma_abc_g1 = Vec::with_capacity(qap.num_public_inputs);
+    let mut l_query = Vec::with_capacity(qap.num_variables() - qap.num_public_inputs);
+
+    for i in 0..qap.num_variables() {
+        a_query.push(linear_combination_homomorphic_poly_eval_g1::<P>(
+            &qap.a[i],
+            t_g1.clone(),
+        ));
+        b_query_g1.push(linear_combination_homomorphic_poly_eval_g1::<P>(
+            &qap.b[i],
+            t_g1.clone(),
+        ));
+        b_query_g2.push(linear_combination_homomorphic_poly_eval_g2::<P>(
+            &qap.b[i],
+            t_g2.clone(),
+        ));
+
+        let l_i_g1 = compute_l_i_of_tau_g1::<P>(
+            &qap.a[i],
+            &qap.b[i],
+            &qap.c[i],
+            alpha_t_g1.clone(),
+            beta_t_g1.clone(),
+            t_g1.clone(),
+        );
+
+        if i < qap.num_public_inputs {
+            gamma_abc_g1.push(l_i_g1.mul_bigint(gamma_inv.into_bigint()));
+        } else {
+            l_query.push(l_i_g1.mul_bigint(delta_inv.into_bigint()));
+        }
+    }
+
+    // [tau^i * t(tau) / delta]_1 for i in 0..(domain.size - 1), the degrees
+    // that h(x) = (A(x)*B(x) - C(x)) / t(x) can actually take.
+    let t_at_tau_over_delta = domain.vanishing_poly_eval(tau) * delta_inv;
+    let mut h_query = Vec::with_capacity(domain.size - 1);
+    let mut tau_power = P::ScalarField::one();
+    for _ in 0..domain.size - 1 {
+        h_query.push(generator_g1.mul_bigint((tau_power * t_at_tau_over_delta).into_bigint()));
+        tau_power *= tau;
+    }
+
+    let proving_key = ProvingKey {
+        alpha_g1,
+        beta_g1,
+        beta_g2,
+        delta_g1,
+        delta_g2,
+        a_query,
+        b_query_g1,
+        b_query_g2,
+        h_query,
+        l_query,
+    };
+
+    let verifying_key = VerifyingKey {
+        alpha_g1_beta_g2: P::pairing(alpha_g1, beta_g2),
+        gamma_g2,
+        delta_g2,
+        gamma_abc_g1,
+    };
+
+    (proving_key, verifying_key)
+}
@@ -1,6 +1,8 @@
 use ark_ec::{pairing::Pairing, Group};
 use ark_ff::PrimeField;
-use polynomial::{interface::UnivariantPolynomialInterface, univariant::UnivariantPolynomial};
+use polynomial::{
+    domain::EvaluationDomain, interface::UnivariantPolynomialInterface, univariant::UnivariantPolynomial,
+};
 
 /// This function generates the t-polynomial for the circuit
 /// we get this;
@@ -87,6 +89,26 @@ pub fn generate_powers_of_tau_g1_alpha_or_beta<P: Pairing>(
     powers_of_tau_g1_alpha_or_beta
 }
 
+/// Same as `linear_combination_homomorphic_poly_eval_g1` but dotted against
+/// `G2` powers, needed to commit the `B` polynomials of the QAP to `G2` for
+/// the Groth16 proving/verifying keys.
+pub fn linear_combination_homomorphic_poly_eval_g2<P>(
+    poly: &UnivariantPolynomial<P::ScalarField>,
+    powers_of_secret_gx: Vec<P::G2>,
+) -> P::G2
+where
+    P: Pairing,
+{
+    poly.coefficients
+        .iter()
+        .enumerate()
+        .fold(P::G2::default(), |mut acc, (index, coeff)| {
+            let res = powers_of_secret_gx[index].mul_bigint(coeff.into_bigint());
+            acc = acc + res;
+            acc
+        })
+}
+
 pub fn compute_l_i_of_tau_g1<P: Pairing>(
     a_poly_i: &UnivariantPolynomial<P::ScalarField>,
     b_poly_i: &UnivariantPolynomial<P::ScalarField>,
@@ -102,6 +124,53 @@ pub fn compute_l_i_of_tau_g1<P: Pairing>(
     beta_a_i_of_tau + alpha_b_i_of_tau + c_i_of_tau
 }
 
+/// Computes `h(x) = (A(x)*B(x) - C(x)) / t(x)` for the witness-combined `A`,
+/// `B`, `C` polynomials of a QAP, where `t` is the vanishing polynomial of
+/// `domain`. `t` is zero everywhere on `domain`, so dividing there is a
+/// `0/0`; instead the three polynomials are moved, via `coset_fft`, to a
+/// coset twice the size of `domain`, where `t` never vanishes, divided
+/// pointwise, then brought back to coefficients with `coset_ifft`.
+pub fn compute_quotient_over_domain<F: PrimeField>(
+    a_poly: &UnivariantPolynomial<F>,
+    b_poly: &UnivariantPolynomial<F>,
+    c_poly: &UnivariantPolynomial<F>,
+    domain: &EvaluationDomain<F>,
+) -> UnivariantPolynomial<F> {
+    let coset_domain = EvaluationDomain::<F>::new(2 * domain.size);
+
+    let a_evals = coset_domain.coset_fft(a_poly);
+    let b_evals = coset_domain.coset_fft(b_poly);
+    let c_evals = coset_domain.coset_fft(c_poly);
+
+    // t(x) at x = GENERATOR * omega_{2m}^k is GENERATOR^m * (-1)^k - 1, since
+    // omega_{2m}^m is the unique square root of unity other than 1. So the
+    // inverse only takes two distinct values, alternating with the parity of `k`.
+    let generator_pow_m = F::GENERATOR.pow([domain.size as u64]);
+    let z_inv_even = (generator_pow_m - F::one()).inverse().unwrap();
+    let z_inv_odd = (-generator_pow_m - F::one()).inverse().unwrap();
+
+    let h_evals: Vec<F> = a_evals
+        .iter()
+        .zip(&b_evals)
+        .zip(&c_evals)
+        .enumerate()
+        .map(|(k, ((a, b), c))| {
+            let z_inv = if k % 2 == 0 { z_inv_even } else { z_inv_odd };
+            (*a * b - c) * z_inv
+        })
+        .collect();
+
+    // `deg(A*B - C) <= 2*(domain.size - 1)` and `deg(t) == domain.size`, so
+    // `h` has at most `domain.size - 1` coefficients; `coset_ifft` returns a
+    // dense vector the size of `coset_domain` (`2 * domain.size`), so the
+    // trailing, mathematically-zero coefficients are trimmed here. Without
+    // this, a caller zipping `h`'s coefficients against the shorter
+    // `h_query` powers (sized to `domain.size - 1`) would panic.
+    let mut h_poly = coset_domain.coset_ifft(&h_evals);
+    h_poly.coefficients.truncate(domain.size - 1);
+    h_poly
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,7 +229,27 @@ mod tests {
 
         assert_eq!(res, expected_res);
     }
-    
+
+    #[test]
+    fn test_linear_combination_homomorphic_poly_eval_g2() {
+        let powers_of_tau_g2 =
+            generate_powers_of_tau_g2::<ark_test_curves::bls12_381::Bls12_381>(Fr::from(5u64), 3);
+        let poly = UnivariantPolynomial::from_coefficients_vec(vec![
+            Fr::from(1),
+            Fr::from(3),
+            Fr::from(2),
+        ]);
+        let res = linear_combination_homomorphic_poly_eval_g2::<
+            ark_test_curves::bls12_381::Bls12_381,
+        >(&poly, powers_of_tau_g2);
+
+        let generator = ark_test_curves::bls12_381::g2::G2Affine::generator();
+        let poly_at_tau = poly.evaluate(&Fr::from(5u64));
+        let expected_res = generator.mul_bigint(poly_at_tau.into_bigint());
+
+        assert_eq!(res, expected_res);
+    }
+
     #[test]
     fn test_compute_l_i_of_tau_g1() {
         let a_i = UnivariantPolynomial::from_coefficients_vec(vec![
@@ -202,4 +291,33 @@ mod tests {
         
         assert_eq!(res, expected_res);
     }
+
+    #[test]
+    fn test_compute_quotient_over_domain() {
+        // a(x) = x + 1, b(x) = x + 2, c(x) chosen so that a*b - c is exactly
+        // divisible by the domain's vanishing polynomial: c = a*b - (x^4 - 1).
+        let domain = EvaluationDomain::<Fr>::new(4);
+
+        let a_poly =
+            UnivariantPolynomial::from_coefficients_vec(vec![Fr::from(1), Fr::from(1)]);
+        let b_poly =
+            UnivariantPolynomial::from_coefficients_vec(vec![Fr::from(2), Fr::from(1)]);
+        let a_mul_b = a_poly.clone() * b_poly.clone();
+        let vanishing = UnivariantPolynomial::from_coefficients_vec(vec![
+            -Fr::from(1),
+            Fr::from(0),
+            Fr::from(0),
+            Fr::from(0),
+            Fr::from(1),
+        ]);
+        let c_poly = a_mul_b + (vanishing.clone() * -Fr::from(1));
+
+        let h_poly = compute_quotient_over_domain(&a_poly, &b_poly, &c_poly, &domain);
+
+        let tau = Fr::from(7u64);
+        let lhs = a_poly.evaluate(&tau) * b_poly.evaluate(&tau) - c_poly.evaluate(&tau);
+        let rhs = h_poly.evaluate(&tau) * domain.vanishing_poly_eval(tau);
+
+        assert_eq!(lhs, rhs);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+
+use crate::{keys::VerifyingKey, prover::Proof};
+
+/// Checks `e(A,B) = e(alpha,beta) * e(sum(public_i * L_i) / gamma, gamma) * e(C, delta)`.
+pub fn verify<P: Pairing>(vk: &VerifyingKey<P>, public_inputs: &[P::ScalarField], proof: &Proof<P>) -> bool {
+    assert_eq!(public_inputs.len(), vk.gamma_abc_g1.len() - 1);
+
+    let public_acc = public_inputs
+        .iter()
+        .zip(vk.gamma_abc_g1.iter().skip(1))
+        .fold(vk.gamma_abc_g1[0], |acc, (input, l_i)| acc + l_i.mul_bigint(input.into_bigint()));
+
+    let lhs = P::pairing(proof.a, proof.b);
+    let rhs = vk.alpha_g1_beta_g2 + P::pairing(public_acc, vk.gamma_g2) + P::pairing(proof.c, vk.delta_g2);
+
+    lhs == rhs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prover::prove, qap::Qap, setup::generate_parameters};
+    use ark_std::test_rng;
+    use ark_test_curves::bls12_381::{Bls12_381, Fr};
+    use polynomial::univariant::UnivariantPolynomial;
+
+    // One constraint, domain = {1}: wires are [one, a, b, c] with
+    // `a` public and `b`, `c` private, enforcing `a * b = c`.
+    fn mul_qap() -> Qap<Fr> {
+        let constant = |value: Fr| UnivariantPolynomial::from_coefficients_vec(vec![value]);
+
+        Qap {
+            num_constraints: 1,
+            num_public_inputs: 2,
+            a: vec![constant(Fr::from(0)), constant(Fr::from(1)), constant(Fr::from(0)), constant(Fr::from(0))],
+            b: vec![constant(Fr::from(0)), constant(Fr::from(0)), constant(Fr::from(1)), constant(Fr::from(0))],
+            c: vec![constant(Fr::from(0)), constant(Fr::from(0)), constant(Fr::from(0)), constant(Fr::from(1))],
+        }
+    }
+
+    #[test]
+    fn test_prove_and_verify_round_trip() {
+        let qap = mul_qap();
+        let rng = &mut test_rng();
+        let (pk, vk) = generate_parameters::<Bls12_381, _>(&qap, rng);
+
+        // 1, a = 3, b = 4, c = a * b = 12
+        let witness = vec![Fr::from(1), Fr::from(3), Fr::from(4), Fr::from(12)];
+        let proof = prove::<Bls12_381, _>(&pk, &qap, &witness, rng);
+
+        assert!(verify(&vk, &[Fr::from(3)], &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_public_input() {
+        let qap = mul_qap();
+        let rng = &mut test_rng();
+        let (pk, vk) = generate_parameters::<Bls12_381, _>(&qap, rng);
+
+        let witness = vec![Fr::from(1), Fr::from(3), Fr::from(4), Fr::from(12)];
+        let proof = prove::<Bls12_381, _>(&pk, &qap, &witness, rng);
+
+        assert!(!verify(&vk, &[Fr::from(5)], &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_proof() {
+        let qap = mul_qap();
+        let rng = &mut test_rng();
+        let (pk, vk) = generate_parameters::<Bls12_381, _>(&qap, rng);
+
+        let witness = vec![Fr::from(1), Fr::from(3), Fr::from(4), Fr::from(12)];
+        let mut proof = prove::<Bls12_381, _>(&pk, &qap, &witness, rng);
+        proof.c = proof.c + pk.delta_g1;
+
+        assert!(!verify(&vk, &[Fr::from(3)], &proof));
+    }
+
+    // Two constraints, domain = {1, -1} (the square roots of unity
+    // `EvaluationDomain::new(2)` builds). Wires are [one, a, b, d] with `a`
+    // public and `b`, `d` private: constraint at `x = 1` enforces `a * a = b`,
+    // constraint at `x = -1` enforces `b * b = d`. Unlike `mul_qap`
+    // (`num_constraints == 1`, where `h_query` is empty and `h(x)` is
+    // trivially the zero polynomial), this exercises a genuine non-zero
+    // `h(x)` through the coset-FFT quotient in `compute_quotient_over_domain`.
+    fn pow4_qap() -> Qap<Fr> {
+        let zero = UnivariantPolynomial::from_coefficients_vec(vec![Fr::from(0)]);
+        let half = Fr::from(2).inverse().unwrap();
+        // L1(x) = (x + 1) / 2: L1(1) = 1, L1(-1) = 0
+        let l1 = UnivariantPolynomial::from_coefficients_vec(vec![half, half]);
+        // L2(x) = (1 - x) / 2: L2(1) = 0, L2(-1) = 1
+        let l2 = UnivariantPolynomial::from_coefficients_vec(vec![half, -half]);
+
+        Qap {
+            num_constraints: 2,
+            num_public_inputs: 2,
+            a: vec![zero.clone(), l1.clone(), l2.clone(), zero.clone()],
+            b: vec![zero.clone(), l1.clone(), l2.clone(), zero.clone()],
+            c: vec![zero.clone(), zero, l1, l2],
+        }
+    }
+
+    #[test]
+    fn test_prove_and_verify_nontrivial_h() {
+        let qap = pow4_qap();
+        let rng = &mut test_rng();
+        let (pk, vk) = generate_parameters::<Bls12_381, _>(&qap, rng);
+
+        // 1, a = 2, b = a * a = 4, d = b * b = 16
+        let witness = vec![Fr::from(1), Fr::from(2), Fr::from(4), Fr::from(16)];
+        let proof = prove::<Bls12_381, _>(&pk, &qap, &witness, rng);
+
+        assert!(verify(&vk, &[Fr::from(2)], &proof));
+    }
+}
@@ -2,60 +2,137 @@ use crate::interface::MultilinearPolynomialInterface;
 use crate::multilinear::Multilinear;
 use ark_ff::PrimeField;
 
-/// This is a composition of multilinear polynomials whose binding operation is multiplication
+/// A sum of products of multilinear polynomials: `Σ_t Π_p terms[t][p]`.
+/// Each inner `Vec` is one product term (its binding operation is
+/// multiplication); the outer `Vec` sums those terms. A single-term
+/// composition (built via `new`) is the pure-product case the sumcheck
+/// round polynomial multiplies together.
 pub struct ComposedMultilinear<F: PrimeField> {
-    /// These are all the multilinear polynomials
-    pub polys: Vec<Multilinear<F>>,
+    pub terms: Vec<Vec<Multilinear<F>>>,
 }
 
 impl<F: PrimeField> ComposedMultilinear<F> {
-    /// This is the constructor for the composed multilinear polynomial
+    /// Constructor for a single product term (all `polys` multiplied together).
     pub fn new(polys: Vec<Multilinear<F>>) -> Self {
         // check to see that all the polynomials have the same number of variables
         let n_vars = polys[0].num_vars();
         assert!(polys.iter().all(|p| p.num_vars() == n_vars));
 
-        ComposedMultilinear { polys }
+        ComposedMultilinear { terms: vec![polys] }
     }
 }
 
 impl<F: PrimeField> MultilinearPolynomialInterface<F> for ComposedMultilinear<F> {
     fn num_vars(&self) -> usize {
-        self.polys[0].num_vars()
+        self.terms[0][0].num_vars()
     }
 
     fn partial_evaluation(&self, evaluation_point: F, variable_index: usize) -> Self {
-        todo!()
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| {
+                term.iter()
+                    .map(|poly| poly.partial_evaluation(evaluation_point, variable_index))
+                    .collect()
+            })
+            .collect();
+
+        ComposedMultilinear { terms }
     }
 
     fn partial_evaluations(&self, evaluation_points: Vec<F>, variable_indices: Vec<usize>) -> Self {
-        todo!()
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| {
+                term.iter()
+                    .map(|poly| poly.partial_evaluations(evaluation_points.clone(), variable_indices.clone()))
+                    .collect()
+            })
+            .collect();
+
+        ComposedMultilinear { terms }
     }
 
     fn evaluate(&self, point: &Vec<F>) -> Option<F> {
-        let mut result = F::one();
+        let mut sum = F::zero();
 
-        for poly in &self.polys {
-            let eval = poly.evaluate(point);
-            match eval {
-                Some(val) => result *= val,
-                None => return None,
+        for term in &self.terms {
+            let mut product = F::one();
+            for poly in term {
+                match poly.evaluate(point) {
+                    Some(val) => product *= val,
+                    None => return None,
+                }
             }
+            sum += product;
         }
 
-        Some(result)
+        Some(sum)
     }
 
     fn extend_with_new_variables(&self, num_of_new_variables: usize) -> Self {
-        todo!()
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| {
+                term.iter()
+                    .map(|poly| poly.extend_with_new_variables(num_of_new_variables))
+                    .collect()
+            })
+            .collect();
+
+        ComposedMultilinear { terms }
     }
 
+    /// Sums `self` and `rhs`: every term of each side is extended with the
+    /// other side's variable count and kept as its own term. Both sides are
+    /// extended the same way, so their original variables share positions
+    /// rather than being placed side by side: `self.add_distinct(&rhs)`
+    /// evaluates to `self(x) + rhs(x)`, not `self(x) * rhs(x)`. Variable
+    /// sets that are meant to be disjoint are not separated.
     fn add_distinct(&self, rhs: &Self) -> Self {
-        todo!()
+        let lhs_terms = self.terms.iter().map(|term| {
+            term.iter()
+                .map(|poly| poly.extend_with_new_variables(rhs.num_vars()))
+                .collect()
+        });
+        let rhs_terms = rhs.terms.iter().map(|term| {
+            term.iter()
+                .map(|poly| poly.extend_with_new_variables(self.num_vars()))
+                .collect()
+        });
+
+        ComposedMultilinear {
+            terms: lhs_terms.chain(rhs_terms).collect(),
+        }
     }
 
+    /// Multiplies `self` and `rhs` by distributing:
+    /// `(Σ_i A_i) * (Σ_j B_j) = Σ_i Σ_j A_i * B_j`, each `A_i * B_j`
+    /// becoming one merged product term. As in `add_distinct`, both sides
+    /// are extended the same way, so their original variables share
+    /// positions and are not separated.
     fn mul_distinct(&self, rhs: &Self) -> Self {
-        todo!()
+        let mut terms = Vec::with_capacity(self.terms.len() * rhs.terms.len());
+
+        for lhs_term in &self.terms {
+            for rhs_term in &rhs.terms {
+                let mut merged: Vec<Multilinear<F>> = lhs_term
+                    .iter()
+                    .map(|poly| poly.extend_with_new_variables(rhs.num_vars()))
+                    .collect();
+                merged.extend(
+                    rhs_term
+                        .iter()
+                        .map(|poly| poly.extend_with_new_variables(self.num_vars())),
+                );
+                terms.push(merged);
+            }
+        }
+
+        ComposedMultilinear { terms }
     }
 
     fn interpolate(y_s: &[F]) -> Self {
@@ -63,11 +140,35 @@ impl<F: PrimeField> MultilinearPolynomialInterface<F> for ComposedMultilinear<F>
     }
 
     fn zero(num_vars: usize) -> Self {
-        todo!()
+        ComposedMultilinear {
+            terms: vec![vec![Multilinear::zero(num_vars)]],
+        }
     }
 
+    /// Exhaustively checks every point of the boolean hypercube: a product
+    /// of nonzero factors can still cancel out pointwise across terms, so
+    /// "some factor is `Multilinear::zero`" is sufficient but not necessary
+    /// and can't be used here. This is `O(2^num_vars)`, so it's only meant
+    /// for the small arities this crate's callers actually construct.
     fn is_zero(&self) -> bool {
-        todo!()
+        let n_vars = self.num_vars();
+        let num_points = 1u64
+            .checked_shl(n_vars as u32)
+            .expect("is_zero is only defined for small, enumerable variable counts");
+
+        (0..num_points).all(|assignment| {
+            let point = (0..n_vars)
+                .map(|bit| {
+                    if (assignment >> bit) & 1 == 1 {
+                        F::one()
+                    } else {
+                        F::zero()
+                    }
+                })
+                .collect();
+
+            self.evaluate(&point) == Some(F::zero())
+        })
     }
 
     fn internal_add(&self, rhs: &Self) -> Self {
@@ -79,7 +180,19 @@ impl<F: PrimeField> MultilinearPolynomialInterface<F> for ComposedMultilinear<F>
     }
 
     fn to_bytes(&self) -> Vec<u8> {
-        todo!()
+        self.terms
+            .iter()
+            .flat_map(|term| term.iter().flat_map(|poly| poly.to_bytes()))
+            .collect()
+    }
+}
+
+impl<F: PrimeField> ComposedMultilinear<F> {
+    /// The degree of the per-round univariate a sumcheck prover produces
+    /// when binding one variable at a time: the largest number of factors
+    /// multiplied together in any single term.
+    pub fn degree(&self) -> usize {
+        self.terms.iter().map(|term| term.len()).max().unwrap_or(0)
     }
 }
 
@@ -98,4 +211,73 @@ mod tests {
         let eval = composed.evaluate(&vec![Fr::from(2), Fr::from(3)]);
         assert_eq!(eval, Some(Fr::from(42)));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_partial_evaluation() {
+        let poly1 = Multilinear::new(vec![Fr::from(0), Fr::from(1), Fr::from(2), Fr::from(3)], 2);
+        let poly2 = Multilinear::new(vec![Fr::from(0), Fr::from(0), Fr::from(0), Fr::from(1)], 2);
+
+        let composed = ComposedMultilinear::new(vec![poly1, poly2]);
+        let partial = composed.partial_evaluation(Fr::from(2), 0);
+
+        let eval = partial.evaluate(&vec![Fr::from(3)]);
+        assert_eq!(eval, composed.evaluate(&vec![Fr::from(2), Fr::from(3)]));
+    }
+
+    #[test]
+    fn test_is_zero() {
+        let poly1 = Multilinear::new(vec![Fr::from(0), Fr::from(1), Fr::from(2), Fr::from(3)], 2);
+        let poly2 = Multilinear::zero(2);
+
+        let composed = ComposedMultilinear::new(vec![poly1, poly2]);
+        assert!(composed.is_zero());
+    }
+
+    #[test]
+    fn test_is_zero_rejects_nonzero_product() {
+        // Neither factor is the zero polynomial, and their product is
+        // 1, 4, 9, 16 on the boolean hypercube, so `is_zero` must be false.
+        let poly1 = Multilinear::new(vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)], 2);
+        let poly2 = Multilinear::new(vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)], 2);
+
+        let composed = ComposedMultilinear::new(vec![poly1, poly2]);
+        assert!(!composed.is_zero());
+    }
+
+    #[test]
+    fn test_add_distinct_sums_instead_of_multiplying() {
+        let poly1 = Multilinear::new(vec![Fr::from(1), Fr::from(2)], 1);
+        let poly2 = Multilinear::new(vec![Fr::from(3), Fr::from(4)], 1);
+
+        let lhs = ComposedMultilinear::new(vec![poly1.clone()]);
+        let rhs = ComposedMultilinear::new(vec![poly2.clone()]);
+
+        let combined = lhs.add_distinct(&rhs);
+
+        // `combined` has two variables, and `lhs` and `rhs` keep their
+        // original variable in the same position. Binding both to the same
+        // point should give `poly1(x) + poly2(x)`, not `poly1(x) * poly2(x)`.
+        let x = Fr::from(0);
+        let eval = combined.evaluate(&vec![x, x]);
+        let expected = poly1.evaluate(&vec![x]).unwrap() + poly2.evaluate(&vec![x]).unwrap();
+
+        assert_eq!(eval, Some(expected));
+    }
+
+    #[test]
+    fn test_mul_distinct_distributes_over_sum() {
+        let poly1 = Multilinear::new(vec![Fr::from(1), Fr::from(2)], 1);
+        let poly2 = Multilinear::new(vec![Fr::from(3), Fr::from(4)], 1);
+
+        let sum = ComposedMultilinear::new(vec![poly1.clone()]).add_distinct(&ComposedMultilinear::new(vec![poly2.clone()]));
+        let product = sum.mul_distinct(&ComposedMultilinear::new(vec![poly1.clone()]));
+
+        let x = Fr::from(1);
+        let eval = product.evaluate(&vec![x, x, x]);
+        let p1 = poly1.evaluate(&vec![x]).unwrap();
+        let p2 = poly2.evaluate(&vec![x]).unwrap();
+        let expected = (p1 + p2) * p1;
+
+        assert_eq!(eval, Some(expected));
+    }
+}
@@ -0,0 +1,222 @@
+use ark_ff::{FftField, PrimeField};
+
+use crate::{interface::UnivariantPolynomialInterface, univariant::UnivariantPolynomial};
+
+/// A multiplicative subgroup of size `m = 2^exp` used to perform polynomial
+/// multiplication and interpolation in `O(n log n)` via the radix-2 NTT,
+/// instead of the `O(n^2)` Lagrange basis / vanishing-polynomial product in
+/// `utils::get_langrange_basis` and `groth16::utils::generate_t_poly`.
+pub struct EvaluationDomain<F: PrimeField> {
+    /// size of the domain, a power of two
+    pub size: usize,
+    /// log2(size)
+    pub log_size_of_group: usize,
+    /// generator of the domain, has order `size`
+    pub group_gen: F,
+    /// inverse of `group_gen`
+    pub group_gen_inv: F,
+    /// inverse of `size` as a field element
+    pub size_inv: F,
+    /// inverse of the field's multiplicative generator, used for coset FFTs
+    pub generator_inv: F,
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Builds the smallest power-of-two domain able to hold `needed` elements.
+    pub fn new(needed: usize) -> Self {
+        let m = needed.next_power_of_two();
+        let exp = m.trailing_zeros() as usize;
+        assert!(
+            exp <= F::TWO_ADICITY as usize,
+            "field does not have a subgroup large enough for a domain of size {}",
+            m
+        );
+
+        let mut group_gen = F::TWO_ADIC_ROOT_OF_UNITY;
+        for _ in exp..F::TWO_ADICITY as usize {
+            group_gen.square_in_place();
+        }
+
+        Self {
+            size: m,
+            log_size_of_group: exp,
+            group_gen,
+            group_gen_inv: group_gen.inverse().unwrap(),
+            size_inv: F::from(m as u64).inverse().unwrap(),
+            generator_inv: F::GENERATOR.inverse().unwrap(),
+        }
+    }
+
+    /// Evaluates `poly` (given by its coefficients) at every point of the domain.
+    pub fn fft(&self, poly: &UnivariantPolynomial<F>) -> Vec<F> {
+        let mut v = self.pad_coefficients(poly);
+        Self::_fft(&mut v, self.group_gen);
+        v
+    }
+
+    /// Interpolates the coefficients of the unique polynomial of degree `< size`
+    /// whose evaluations over the domain are `evals`.
+    pub fn ifft(&self, evals: &[F]) -> UnivariantPolynomial<F> {
+        let mut v = evals.to_vec();
+        v.resize(self.size, F::zero());
+        Self::_fft(&mut v, self.group_gen_inv);
+        for coeff in v.iter_mut() {
+            *coeff *= self.size_inv;
+        }
+        UnivariantPolynomial::from_coefficients_vec(v)
+    }
+
+    /// Evaluates `poly` over the coset `generator * domain`, used to avoid
+    /// dividing by the vanishing polynomial where it is zero.
+    pub fn coset_fft(&self, poly: &UnivariantPolynomial<F>) -> Vec<F> {
+        let mut v = self.pad_coefficients(poly);
+        Self::distribute_powers(&mut v, F::GENERATOR);
+        Self::_fft(&mut v, self.group_gen);
+        v
+    }
+
+    /// Inverse of `coset_fft`: interpolates coset evaluations back to coefficients.
+    pub fn coset_ifft(&self, evals: &[F]) -> UnivariantPolynomial<F> {
+        let mut v = evals.to_vec();
+        v.resize(self.size, F::zero());
+        Self::_fft(&mut v, self.group_gen_inv);
+        for coeff in v.iter_mut() {
+            *coeff *= self.size_inv;
+        }
+        Self::distribute_powers(&mut v, self.generator_inv);
+        UnivariantPolynomial::from_coefficients_vec(v)
+    }
+
+    /// `z(tau) = tau^size - 1`, the value at an arbitrary point `tau` of the
+    /// vanishing polynomial of this domain. Computed in one `pow` plus one
+    /// subtraction instead of materializing the `size`-fold product
+    /// `(x - 1)(x - 2)...(x - size)` that `groth16::utils::generate_t_poly`
+    /// builds for the integer points `1..=size`.
+    pub fn vanishing_poly_eval(&self, tau: F) -> F {
+        tau.pow([self.size as u64]) - F::one()
+    }
+
+    /// Sparse coefficient form of the vanishing polynomial, as
+    /// `(degree, coefficient)` pairs: `+1` at degree `size`, `-1` at degree
+    /// `0`, instead of a dense `size + 1` coefficient vector.
+    /// `linear_combination_homomorphic_poly_eval_g1` takes a dense
+    /// `UnivariantPolynomial` and does not accept this form.
+    pub fn vanishing_poly_sparse_coeffs(&self) -> Vec<(usize, F)> {
+        vec![(self.size, F::one()), (0, -F::one())]
+    }
+
+    fn pad_coefficients(&self, poly: &UnivariantPolynomial<F>) -> Vec<F> {
+        let mut v = poly.coefficients.clone();
+        assert!(
+            v.len() <= self.size,
+            "polynomial degree exceeds the domain size"
+        );
+        v.resize(self.size, F::zero());
+        v
+    }
+
+    /// Multiplies coefficient `i` by `base^i`, via a running power, in place.
+    fn distribute_powers(coeffs: &mut [F], base: F) {
+        let mut power = F::one();
+        for coeff in coeffs.iter_mut() {
+            *coeff *= power;
+            power *= base;
+        }
+    }
+
+    /// In-place iterative radix-2 Cooley-Tukey NTT: bit-reversal permutation
+    /// followed by `log2(v.len())` butterfly rounds.
+    fn _fft(v: &mut [F], omega: F) {
+        let n = v.len();
+        if n <= 1 {
+            return;
+        }
+        assert!(n.is_power_of_two());
+
+        let log_n = n.trailing_zeros();
+        for i in 0..n {
+            let j = (i as u32).reverse_bits() >> (32 - log_n);
+            if i < j as usize {
+                v.swap(i, j as usize);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let step = n / len;
+            let w_len = omega.pow([step as u64]);
+            let mut start = 0;
+            while start < n {
+                let mut w = F::one();
+                for k in 0..len / 2 {
+                    let u = v[start + k];
+                    let t = v[start + k + len / 2] * w;
+                    v[start + k] = u + t;
+                    v[start + k + len / 2] = u - t;
+                    w *= w_len;
+                }
+                start += len;
+            }
+            len <<= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn test_fft_ifft_roundtrip() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let poly = UnivariantPolynomial::from_coefficients_vec(vec![
+            Fr::from(1u64),
+            Fr::from(2u64),
+            Fr::from(3u64),
+            Fr::from(4u64),
+        ]);
+
+        let evals = domain.fft(&poly);
+        let recovered = domain.ifft(&evals);
+
+        assert_eq!(recovered, poly);
+    }
+
+    #[test]
+    fn test_coset_fft_ifft_roundtrip() {
+        let domain = EvaluationDomain::<Fr>::new(8);
+        let poly = UnivariantPolynomial::from_coefficients_vec(vec![
+            Fr::from(5u64),
+            Fr::from(0u64),
+            Fr::from(1u64),
+        ]);
+
+        let evals = domain.coset_fft(&poly);
+        let recovered = domain.coset_ifft(&evals);
+
+        assert_eq!(recovered, poly);
+    }
+
+    #[test]
+    fn test_vanishing_poly_eval() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let tau = Fr::from(7u64);
+
+        assert_eq!(domain.vanishing_poly_eval(tau), tau.pow([4u64]) - Fr::from(1u64));
+    }
+
+    #[test]
+    fn test_vanishing_poly_sparse_coeffs_matches_dense_eval() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let tau = Fr::from(7u64);
+
+        let sparse_eval: Fr = domain
+            .vanishing_poly_sparse_coeffs()
+            .into_iter()
+            .map(|(degree, coeff)| coeff * tau.pow([degree as u64]))
+            .sum();
+
+        assert_eq!(sparse_eval, domain.vanishing_poly_eval(tau));
+    }
+}